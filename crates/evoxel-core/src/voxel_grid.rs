@@ -5,7 +5,7 @@ use chrono::{DateTime, Utc};
 use ecoord::{FrameId, ReferenceFrames, TransformId};
 use nalgebra::Point3;
 use polars::datatypes::PlSmallStr;
-use polars::prelude::DataFrame;
+use polars::prelude::{DataFrame, IntoSeries, Series};
 use rayon::prelude::*;
 
 #[derive(Debug, Clone)]
@@ -287,6 +287,68 @@ impl VoxelGrid {
             .collect::<Result<Vec<_>, Error>>()?;
         Ok(center_points)
     }
+
+    fn apply_column_unchecked<F, S>(&mut self, column_name: &str, f: F) -> Result<(), Error>
+    where
+        F: FnOnce(&Series) -> S,
+        S: IntoSeries,
+    {
+        self.voxel_data.apply(column_name, f)?;
+        Ok(())
+    }
+
+    fn check_integrity(&self) -> Result<(), Error> {
+        data_frame_utils::check_data_integrity(&self.voxel_data, &self.info, &self.reference_frames)
+    }
+
+    /// Applies `f` to one of the mandatory index/count columns in place, re-running the data
+    /// integrity check afterwards.
+    ///
+    /// This mutates the backing `DataFrame` directly instead of cloning it, e.g. for rescaling
+    /// counts or offsetting indices. It is not allocation-free: the column `f` returns is a new
+    /// `Series` that replaces the old one. Callers mutating
+    /// several columns at once (e.g. X, Y and Z together) should use [`Self::apply_to_columns`]
+    /// instead, so the integrity check only runs once for the whole batch.
+    pub fn apply_to_column<F, S>(&mut self, column: VoxelDataColumnType, f: F) -> Result<(), Error>
+    where
+        F: FnOnce(&Series) -> S,
+        S: IntoSeries,
+    {
+        self.apply_column_unchecked(column.as_str(), f)?;
+        self.check_integrity()
+    }
+
+    /// Applies a mutation to each of several mandatory columns in place, running the data
+    /// integrity check once after all of them have been applied.
+    ///
+    /// This is the batched counterpart to [`Self::apply_to_column`]: mutating X, Y and Z through
+    /// three separate `apply_to_column` calls would re-run the (whole-frame) integrity check
+    /// three times, once per column.
+    pub fn apply_to_columns<'a>(
+        &mut self,
+        mutations: impl IntoIterator<
+            Item = (VoxelDataColumnType, Box<dyn FnOnce(&Series) -> Series + 'a>),
+        >,
+    ) -> Result<(), Error> {
+        for (column, f) in mutations {
+            self.apply_column_unchecked(column.as_str(), f)?;
+        }
+        self.check_integrity()
+    }
+
+    /// Applies `f` to an arbitrary attribute column in place, re-running the data integrity
+    /// check afterwards.
+    ///
+    /// Unlike [`Self::apply_to_column`], this accepts any column name, including user-defined
+    /// attribute columns that are not part of [`VoxelDataColumnType`].
+    pub fn map_attributes<F, S>(&mut self, column_name: &str, f: F) -> Result<(), Error>
+    where
+        F: FnOnce(&Series) -> S,
+        S: IntoSeries,
+    {
+        self.apply_column_unchecked(column_name, f)?;
+        self.check_integrity()
+    }
 }
 
 const COLUMN_NAME_X_STR: &str = "x";
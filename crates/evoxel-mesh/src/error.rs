@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    EvoxelCoreError(#[from] evoxel_core::Error),
+
+    #[error(transparent)]
+    EcoordError(#[from] ecoord::Error),
+
+    #[error(transparent)]
+    StdIoError(#[from] std::io::Error),
+}
@@ -0,0 +1,49 @@
+use std::io::Write;
+use std::path::Path;
+
+use crate::mesh::Mesh;
+use crate::Error;
+
+/// Writes `mesh` as a Wavefront OBJ file.
+pub fn write_obj(mesh: &Mesh, path: impl AsRef<Path>) -> Result<(), Error> {
+    let mut file = std::fs::File::create(path)?;
+
+    for vertex in mesh.vertices() {
+        writeln!(file, "v {} {} {}", vertex.x, vertex.y, vertex.z)?;
+    }
+    for triangle in mesh.triangles() {
+        writeln!(
+            file,
+            "f {} {} {}",
+            triangle[0] + 1,
+            triangle[1] + 1,
+            triangle[2] + 1
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Writes `mesh` as an ASCII PLY file.
+pub fn write_ply(mesh: &Mesh, path: impl AsRef<Path>) -> Result<(), Error> {
+    let mut file = std::fs::File::create(path)?;
+
+    writeln!(file, "ply")?;
+    writeln!(file, "format ascii 1.0")?;
+    writeln!(file, "element vertex {}", mesh.vertex_count())?;
+    writeln!(file, "property float x")?;
+    writeln!(file, "property float y")?;
+    writeln!(file, "property float z")?;
+    writeln!(file, "element face {}", mesh.triangle_count())?;
+    writeln!(file, "property list uchar int vertex_index")?;
+    writeln!(file, "end_header")?;
+
+    for vertex in mesh.vertices() {
+        writeln!(file, "{} {} {}", vertex.x, vertex.y, vertex.z)?;
+    }
+    for triangle in mesh.triangles() {
+        writeln!(file, "3 {} {} {}", triangle[0], triangle[1], triangle[2])?;
+    }
+
+    Ok(())
+}
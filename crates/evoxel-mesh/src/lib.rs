@@ -0,0 +1,9 @@
+mod error;
+mod io;
+mod marching_cubes;
+mod mesh;
+
+pub use error::Error;
+pub use io::{write_obj, write_ply};
+pub use marching_cubes::{extract_surface, IsoSurfaceOptions};
+pub use mesh::Mesh;
@@ -0,0 +1,312 @@
+use std::collections::{HashMap, HashSet};
+
+use ecoord::{FrameId, TransformId};
+use evoxel_core::VoxelGrid;
+use nalgebra::Point3;
+
+use crate::mesh::Mesh;
+use crate::Error;
+
+/// Local `(x, y, z)` offsets of a lattice cube's 8 corners, indexed the same way as
+/// [`TETRA_CORNERS`].
+const CUBE_CORNER_OFFSETS: [(i64, i64, i64); 8] = [
+    (0, 0, 0),
+    (1, 0, 0),
+    (0, 1, 0),
+    (1, 1, 0),
+    (0, 0, 1),
+    (1, 0, 1),
+    (0, 1, 1),
+    (1, 1, 1),
+];
+
+/// Freudenthal decomposition of a cube into 6 tetrahedra sharing the `0`-`7` diagonal, each
+/// entry naming 4 of the 8 cube corners by their index into [`CUBE_CORNER_OFFSETS`].
+const TETRA_CORNERS: [[usize; 4]; 6] = [
+    [0, 1, 3, 7],
+    [0, 1, 5, 7],
+    [0, 4, 5, 7],
+    [0, 2, 3, 7],
+    [0, 2, 6, 7],
+    [0, 4, 6, 7],
+];
+
+type LatticeIndex = (i64, i64, i64);
+type Edge = (LatticeIndex, LatticeIndex);
+
+/// Options controlling how a surface is extracted from a [`VoxelGrid`] by [`extract_surface`].
+pub struct IsoSurfaceOptions {
+    /// Reference frame the resulting mesh's vertices are expressed in.
+    pub target_frame_id: FrameId,
+}
+
+fn other_corners(excluded: usize) -> [usize; 3] {
+    let mut others = [0usize; 3];
+    let mut cursor = 0;
+    for corner in 0..4 {
+        if corner != excluded {
+            others[cursor] = corner;
+            cursor += 1;
+        }
+    }
+    others
+}
+
+/// Triangulates a single tetrahedron given its 4 corner indices and occupancy, returning each
+/// triangle as 3 edges between corner positions (the surface crosses exactly these edges).
+fn triangulate_tetrahedron(corners: [(Point3<i64>, bool); 4]) -> Vec<[Edge; 3]> {
+    let edge = |a: usize, b: usize| -> Edge {
+        let p = corners[a].0;
+        let q = corners[b].0;
+        ((p.x, p.y, p.z), (q.x, q.y, q.z))
+    };
+
+    let inside: Vec<usize> = (0..4).filter(|&i| corners[i].1).collect();
+
+    match inside.len() {
+        0 | 4 => Vec::new(),
+        1 => {
+            let i = inside[0];
+            let [j, k, l] = other_corners(i);
+            vec![[edge(i, j), edge(i, k), edge(i, l)]]
+        }
+        3 => {
+            let outside = (0..4).find(|i| !corners[*i].1).unwrap();
+            let [j, k, l] = other_corners(outside);
+            vec![[edge(outside, l), edge(outside, k), edge(outside, j)]]
+        }
+        2 => {
+            let (a, b) = (inside[0], inside[1]);
+            let (c, d) =
+                (0..4)
+                    .filter(|i| !corners[*i].1)
+                    .fold((None, None), |(first, second), i| match (first, second) {
+                        (None, _) => (Some(i), second),
+                        (Some(_), None) => (first, Some(i)),
+                        _ => (first, second),
+                    });
+            let (c, d) = (c.unwrap(), d.unwrap());
+            vec![
+                [edge(a, c), edge(b, c), edge(a, d)],
+                [edge(b, c), edge(b, d), edge(a, d)],
+            ]
+        }
+        _ => unreachable!("a tetrahedron has exactly 4 corners"),
+    }
+}
+
+/// Converts a [`VoxelGrid`]'s occupancy into a watertight triangle mesh.
+///
+/// This is marching *tetrahedra*, not classic marching cubes: rather than indexing an 8-bit
+/// corner mask into the standard 256-entry edge/triangle tables, every 2×2×2 lattice cell with
+/// at least one occupied corner is split into 6 tetrahedra (the standard Freudenthal decomposition of a cube),
+/// and each tetrahedron's 4 corners are classified occupied/empty to pick the triangles crossing
+/// it directly (see [`triangulate_tetrahedron`]) — a smaller, ambiguity-free case table (16
+/// entries instead of 256) at the cost of more triangles per cell. Triangle vertices sit at the
+/// midpoints of the active lattice edges, scaled by [`VoxelGridInfo::resolution`][res] and
+/// deduplicated through a `HashMap` keyed on the edge's endpoints so shared edges between
+/// neighboring cells produce a single, indexed vertex. Vertices are finally transformed into
+/// `options.target_frame_id` using the grid's reference frames.
+///
+/// [res]: evoxel_core::VoxelGridInfo::resolution
+pub fn extract_surface(voxel_grid: &VoxelGrid, options: IsoSurfaceOptions) -> Result<Mesh, Error> {
+    let occupied: HashSet<LatticeIndex> = voxel_grid
+        .get_all_cell_indices_in_local_frame()
+        .into_iter()
+        .map(|index| (index.x, index.y, index.z))
+        .collect();
+
+    let mut cube_origins: HashSet<LatticeIndex> = HashSet::new();
+    for &(x, y, z) in &occupied {
+        for dx in -1..=0i64 {
+            for dy in -1..=0i64 {
+                for dz in -1..=0i64 {
+                    cube_origins.insert((x + dx, y + dy, z + dz));
+                }
+            }
+        }
+    }
+
+    let mut vertex_lookup: HashMap<Edge, u32> = HashMap::new();
+    let mut vertices: Vec<Point3<f64>> = Vec::new();
+    let mut triangles: Vec<[u32; 3]> = Vec::new();
+    let resolution = voxel_grid.info().resolution;
+
+    for &(origin_x, origin_y, origin_z) in &cube_origins {
+        let corners: [(Point3<i64>, bool); 8] = std::array::from_fn(|corner| {
+            let (dx, dy, dz) = CUBE_CORNER_OFFSETS[corner];
+            let index = (origin_x + dx, origin_y + dy, origin_z + dz);
+            (
+                Point3::new(index.0, index.1, index.2),
+                occupied.contains(&index),
+            )
+        });
+
+        for tetra in &TETRA_CORNERS {
+            let tetra_corners: [(Point3<i64>, bool); 4] =
+                std::array::from_fn(|i| corners[tetra[i]]);
+
+            for triangle_edges in triangulate_tetrahedron(tetra_corners) {
+                let triangle_indices: [u32; 3] = std::array::from_fn(|i| {
+                    let (p, q) = triangle_edges[i];
+                    let key = if p <= q { (p, q) } else { (q, p) };
+                    *vertex_lookup.entry(key).or_insert_with(|| {
+                        let midpoint = Point3::new(
+                            (key.0 .0 + key.1 .0) as f64 / 2.0,
+                            (key.0 .1 + key.1 .1) as f64 / 2.0,
+                            (key.0 .2 + key.1 .2) as f64 / 2.0,
+                        );
+                        vertices.push(midpoint * resolution);
+                        (vertices.len() - 1) as u32
+                    })
+                });
+                triangles.push(triangle_indices);
+            }
+        }
+    }
+
+    let isometry_graph = voxel_grid
+        .reference_frames()
+        .derive_transform_graph(&None, &None)?;
+    let transform_id = TransformId::new(
+        options.target_frame_id.clone(),
+        voxel_grid.info().frame_id.clone(),
+    );
+    let isometry = isometry_graph.get_isometry(&transform_id)?;
+    let transformed_vertices: Vec<Point3<f64>> =
+        vertices.iter().map(|vertex| isometry * vertex).collect();
+
+    Ok(Mesh::new(
+        transformed_vertices,
+        triangles,
+        options.target_frame_id,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corner(x: i64, y: i64, z: i64, inside: bool) -> (Point3<i64>, bool) {
+        (Point3::new(x, y, z), inside)
+    }
+
+    #[test]
+    fn other_corners_excludes_only_the_given_index() {
+        for excluded in 0..4 {
+            let others = other_corners(excluded);
+            assert_eq!(others.len(), 3);
+            assert!(!others.contains(&excluded));
+        }
+    }
+
+    #[test]
+    fn triangulate_tetrahedron_all_inside_or_all_outside_yields_no_triangles() {
+        let all_outside = [
+            corner(0, 0, 0, false),
+            corner(1, 0, 0, false),
+            corner(0, 1, 0, false),
+            corner(0, 0, 1, false),
+        ];
+        let all_inside = [
+            corner(0, 0, 0, true),
+            corner(1, 0, 0, true),
+            corner(0, 1, 0, true),
+            corner(0, 0, 1, true),
+        ];
+        assert!(triangulate_tetrahedron(all_outside).is_empty());
+        assert!(triangulate_tetrahedron(all_inside).is_empty());
+    }
+
+    #[test]
+    fn triangulate_tetrahedron_single_inside_corner_yields_one_triangle() {
+        let corners = [
+            corner(0, 0, 0, true),
+            corner(1, 0, 0, false),
+            corner(0, 1, 0, false),
+            corner(0, 0, 1, false),
+        ];
+        let triangles = triangulate_tetrahedron(corners);
+        assert_eq!(triangles.len(), 1);
+
+        let triangle_edges: HashSet<Edge> = triangles[0]
+            .iter()
+            .map(|&(a, b)| if a <= b { (a, b) } else { (b, a) })
+            .collect();
+        assert_eq!(
+            triangle_edges,
+            HashSet::from([
+                ((0, 0, 0), (1, 0, 0)),
+                ((0, 0, 0), (0, 1, 0)),
+                ((0, 0, 0), (0, 0, 1)),
+            ])
+        );
+    }
+
+    #[test]
+    fn triangulate_tetrahedron_single_outside_corner_yields_one_triangle() {
+        let corners = [
+            corner(0, 0, 0, false),
+            corner(1, 0, 0, true),
+            corner(0, 1, 0, true),
+            corner(0, 0, 1, true),
+        ];
+        let triangles = triangulate_tetrahedron(corners);
+        assert_eq!(triangles.len(), 1);
+
+        let triangle_edges: HashSet<Edge> = triangles[0]
+            .iter()
+            .map(|&(a, b)| if a <= b { (a, b) } else { (b, a) })
+            .collect();
+        assert_eq!(
+            triangle_edges,
+            HashSet::from([
+                ((0, 0, 0), (1, 0, 0)),
+                ((0, 0, 0), (0, 1, 0)),
+                ((0, 0, 0), (0, 0, 1)),
+            ])
+        );
+    }
+
+    #[test]
+    fn triangulate_tetrahedron_two_inside_two_outside_yields_two_triangles_spanning_all_four_cross_edges(
+    ) {
+        let corners = [
+            corner(0, 0, 0, true),
+            corner(1, 0, 0, true),
+            corner(0, 1, 0, false),
+            corner(0, 0, 1, false),
+        ];
+        let triangles = triangulate_tetrahedron(corners);
+        assert_eq!(triangles.len(), 2);
+
+        let edges: HashSet<Edge> = triangles
+            .iter()
+            .flatten()
+            .map(|&(a, b)| if a <= b { (a, b) } else { (b, a) })
+            .collect();
+        let expected_cross_edges = HashSet::from([
+            if (0, 0, 0) <= (0, 1, 0) {
+                ((0, 0, 0), (0, 1, 0))
+            } else {
+                ((0, 1, 0), (0, 0, 0))
+            },
+            if (0, 0, 0) <= (0, 0, 1) {
+                ((0, 0, 0), (0, 0, 1))
+            } else {
+                ((0, 0, 1), (0, 0, 0))
+            },
+            if (1, 0, 0) <= (0, 1, 0) {
+                ((1, 0, 0), (0, 1, 0))
+            } else {
+                ((0, 1, 0), (1, 0, 0))
+            },
+            if (1, 0, 0) <= (0, 0, 1) {
+                ((1, 0, 0), (0, 0, 1))
+            } else {
+                ((0, 0, 1), (1, 0, 0))
+            },
+        ]);
+        assert_eq!(edges, expected_cross_edges);
+    }
+}
@@ -0,0 +1,41 @@
+use ecoord::FrameId;
+use nalgebra::Point3;
+
+/// An indexed triangle mesh, e.g. extracted from a [`evoxel_core::VoxelGrid`] by
+/// [`crate::extract_surface`].
+#[derive(Debug, Clone)]
+pub struct Mesh {
+    vertices: Vec<Point3<f64>>,
+    triangles: Vec<[u32; 3]>,
+    frame_id: FrameId,
+}
+
+impl Mesh {
+    pub fn new(vertices: Vec<Point3<f64>>, triangles: Vec<[u32; 3]>, frame_id: FrameId) -> Self {
+        Self {
+            vertices,
+            triangles,
+            frame_id,
+        }
+    }
+
+    pub fn vertices(&self) -> &[Point3<f64>] {
+        &self.vertices
+    }
+
+    pub fn triangles(&self) -> &[[u32; 3]] {
+        &self.triangles
+    }
+
+    pub fn frame_id(&self) -> &FrameId {
+        &self.frame_id
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.triangles.len()
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::{HashMap, VecDeque};
+
+use evoxel_core::VoxelGrid;
+use nalgebra::Point3;
+use polars::prelude::Series;
+
+use crate::Error;
+
+const COLUMN_NAME_LABEL_STR: &str = "label";
+const COLUMN_NAME_DISTANCE_STR: &str = "distance";
+
+/// Neighborhood used to decide whether two voxels are adjacent.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Connectivity {
+    /// Face neighbors only: ±1 along a single axis (6 neighbors).
+    Six,
+    /// Every neighbor in the surrounding 3×3×3 block except the center (26 neighbors).
+    TwentySix,
+}
+
+impl Connectivity {
+    pub(crate) fn offsets(&self) -> Vec<(i64, i64, i64)> {
+        match self {
+            Connectivity::Six => vec![
+                (1, 0, 0),
+                (-1, 0, 0),
+                (0, 1, 0),
+                (0, -1, 0),
+                (0, 0, 1),
+                (0, 0, -1),
+            ],
+            Connectivity::TwentySix => {
+                let mut offsets = Vec::with_capacity(26);
+                for dx in -1..=1i64 {
+                    for dy in -1..=1i64 {
+                        for dz in -1..=1i64 {
+                            if dx != 0 || dy != 0 || dz != 0 {
+                                offsets.push((dx, dy, dz));
+                            }
+                        }
+                    }
+                }
+                offsets
+            }
+        }
+    }
+}
+
+/// Maps each occupied voxel's integer index to its row within `voxel_grid`.
+///
+/// Indices are expected to be unique; run [`crate::aggregate_by_index`] first if the grid may
+/// contain duplicates, otherwise one of the colliding rows is silently shadowed.
+pub(crate) fn build_index_map(voxel_grid: &VoxelGrid) -> HashMap<(i64, i64, i64), usize> {
+    voxel_grid
+        .get_all_cell_indices_in_local_frame()
+        .into_iter()
+        .enumerate()
+        .map(|(row, index)| ((index.x, index.y, index.z), row))
+        .collect()
+}
+
+/// Labels each occupied voxel with the id of the connected component it belongs to, returning
+/// the relabeled grid alongside the size (voxel count) of each component, indexed by label.
+///
+/// Components are grown with an iterative BFS over `connectivity`, seeded in ascending
+/// `(x, y, z)` order rather than row order, so labels are stable across runs regardless of how
+/// the backing `DataFrame` happens to be ordered (e.g. after a non order-preserving
+/// [`crate::aggregate_by_index`]). The result is `voxel_grid` with an additional `label` column.
+pub fn connected_components(
+    voxel_grid: &VoxelGrid,
+    connectivity: Connectivity,
+) -> Result<(VoxelGrid, Vec<usize>), Error> {
+    let index_map = build_index_map(voxel_grid);
+    let offsets = connectivity.offsets();
+
+    let mut seed_order: Vec<usize> = (0..voxel_grid.size()).collect();
+    seed_order.sort_by_key(|&row| {
+        let index = voxel_grid.get_cell_index(row);
+        (index.x, index.y, index.z)
+    });
+
+    let mut labels = vec![-1i64; voxel_grid.size()];
+    let mut component_sizes: Vec<usize> = Vec::new();
+
+    for seed_row in seed_order {
+        if labels[seed_row] != -1 {
+            continue;
+        }
+
+        let current_label = component_sizes.len() as i64;
+        let mut size = 0usize;
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        queue.push_back(seed_row);
+        labels[seed_row] = current_label;
+
+        while let Some(row) = queue.pop_front() {
+            size += 1;
+            let index = voxel_grid.get_cell_index(row);
+
+            for (dx, dy, dz) in &offsets {
+                let neighbor_index = (index.x + dx, index.y + dy, index.z + dz);
+                if let Some(&neighbor_row) = index_map.get(&neighbor_index) {
+                    if labels[neighbor_row] == -1 {
+                        labels[neighbor_row] = current_label;
+                        queue.push_back(neighbor_row);
+                    }
+                }
+            }
+        }
+
+        component_sizes.push(size);
+    }
+
+    tracing::info!(
+        "connected_components: {} component(s) under {:?} connectivity, sizes {:?}",
+        component_sizes.len(),
+        connectivity,
+        component_sizes
+    );
+
+    let mut labeled_data = voxel_grid.voxel_data().clone();
+    labeled_data.with_column(Series::new(COLUMN_NAME_LABEL_STR.into(), labels))?;
+
+    let labeled_voxel_grid = VoxelGrid::new(
+        labeled_data,
+        voxel_grid.info().clone(),
+        voxel_grid.reference_frames().clone(),
+    )?;
+
+    Ok((labeled_voxel_grid, component_sizes))
+}
+
+/// Lattice distance metric used by [`distance_field`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DistanceMetric {
+    /// Shortest path stepping through the 6-neighborhood.
+    Manhattan,
+    /// Shortest path stepping through the 26-neighborhood.
+    Chebyshev,
+}
+
+impl DistanceMetric {
+    fn connectivity(&self) -> Connectivity {
+        match self {
+            DistanceMetric::Manhattan => Connectivity::Six,
+            DistanceMetric::Chebyshev => Connectivity::TwentySix,
+        }
+    }
+}
+
+/// Seed voxels a [`distance_field`] sweep starts from.
+pub enum DistanceSources {
+    /// An explicit set of source voxel indices.
+    Indices(Vec<Point3<i64>>),
+    /// Every occupied voxel missing at least one 6-neighbor, i.e. the boundary of the grid.
+    Boundary,
+}
+
+/// Computes, for every occupied voxel, the integer lattice distance to the nearest source voxel.
+///
+/// A multi-source BFS is seeded from all `sources` at distance 0 and expands through the
+/// neighborhood implied by `metric`; since every step has unit weight, the first time a voxel is
+/// reached is its shortest distance. The result is `voxel_grid` with an additional `distance`
+/// column; voxels unreachable from any source (a disconnected component) keep a distance of -1.
+pub fn distance_field(
+    voxel_grid: &VoxelGrid,
+    metric: DistanceMetric,
+    sources: DistanceSources,
+) -> Result<VoxelGrid, Error> {
+    let index_map = build_index_map(voxel_grid);
+    let offsets = metric.connectivity().offsets();
+
+    let source_rows: Vec<usize> = match sources {
+        DistanceSources::Indices(indices) => indices
+            .iter()
+            .filter_map(|index| index_map.get(&(index.x, index.y, index.z)).copied())
+            .collect(),
+        DistanceSources::Boundary => {
+            let six_offsets = Connectivity::Six.offsets();
+            (0..voxel_grid.size())
+                .filter(|&row| {
+                    let index = voxel_grid.get_cell_index(row);
+                    six_offsets.iter().any(|(dx, dy, dz)| {
+                        !index_map.contains_key(&(index.x + dx, index.y + dy, index.z + dz))
+                    })
+                })
+                .collect()
+        }
+    };
+
+    let mut distances = vec![-1i64; voxel_grid.size()];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    for row in source_rows {
+        if distances[row] == -1 {
+            distances[row] = 0;
+            queue.push_back(row);
+        }
+    }
+
+    while let Some(row) = queue.pop_front() {
+        let current_distance = distances[row];
+        let index = voxel_grid.get_cell_index(row);
+
+        for (dx, dy, dz) in &offsets {
+            let neighbor_index = (index.x + dx, index.y + dy, index.z + dz);
+            if let Some(&neighbor_row) = index_map.get(&neighbor_index) {
+                if distances[neighbor_row] == -1 {
+                    distances[neighbor_row] = current_distance + 1;
+                    queue.push_back(neighbor_row);
+                }
+            }
+        }
+    }
+
+    let mut distance_data = voxel_grid.voxel_data().clone();
+    distance_data.with_column(Series::new(COLUMN_NAME_DISTANCE_STR.into(), distances))?;
+
+    let distance_voxel_grid = VoxelGrid::new(
+        distance_data,
+        voxel_grid.info().clone(),
+        voxel_grid.reference_frames().clone(),
+    )?;
+
+    Ok(distance_voxel_grid)
+}
+
+// `connected_components` and `distance_field` are exercised only indirectly below: both need a
+// `VoxelGrid` fixture, and `evoxel_core`'s `VoxelGridInfo`/`ecoord::ReferenceFrames` constructors
+// aren't part of this tree, so only the pure offset/metric logic they build on is covered here.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn six_connectivity_has_one_offset_per_face() {
+        let offsets = Connectivity::Six.offsets();
+        assert_eq!(offsets.len(), 6);
+        for (dx, dy, dz) in &offsets {
+            assert_eq!(dx.abs() + dy.abs() + dz.abs(), 1);
+        }
+    }
+
+    #[test]
+    fn twenty_six_connectivity_has_one_offset_per_neighbor_excluding_center() {
+        let offsets = Connectivity::TwentySix.offsets();
+        assert_eq!(offsets.len(), 26);
+        assert!(!offsets.contains(&(0, 0, 0)));
+        let unique: HashMap<(i64, i64, i64), ()> =
+            offsets.iter().map(|&offset| (offset, ())).collect();
+        assert_eq!(unique.len(), 26);
+    }
+
+    #[test]
+    fn distance_metric_maps_to_matching_connectivity() {
+        assert_eq!(DistanceMetric::Manhattan.connectivity(), Connectivity::Six);
+        assert_eq!(
+            DistanceMetric::Chebyshev.connectivity(),
+            Connectivity::TwentySix
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use polars::error::PolarsError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("lower corner must be below upper corner: {0}")]
+    LowerCornerMustBeBelowUpperCorner(&'static str),
+
+    #[error("voxel grids must share the same resolution and frame id")]
+    MismatchedVoxelGridInfo,
+
+    #[error("voxel grids must share the same reference frames")]
+    MismatchedReferenceFrames,
+
+    #[error(transparent)]
+    EvoxelCoreError(#[from] evoxel_core::Error),
+
+    #[error(transparent)]
+    PolarsError(#[from] PolarsError),
+}
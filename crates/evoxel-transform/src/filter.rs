@@ -46,6 +46,38 @@ pub fn aggregate_by_index(voxel_grid: &VoxelGrid) -> Result<VoxelGrid, Error> {
     Ok(filtered_voxel_grid)
 }
 
+/// Merges rows sharing the same `(x, y, z)` index like [`aggregate_by_index`], but sums the
+/// `Count` column instead of collecting it into a list, so the result keeps a scalar occupancy
+/// count per voxel. Other attribute columns keep the first value seen for each index.
+pub(crate) fn merge_duplicate_indices_summing_count(
+    voxel_grid: &VoxelGrid,
+) -> Result<VoxelGrid, Error> {
+    let voxel_data = voxel_grid.voxel_data();
+    let partition_columns = vec![
+        VoxelDataColumnType::X.as_str(),
+        VoxelDataColumnType::Y.as_str(),
+        VoxelDataColumnType::Z.as_str(),
+    ];
+    let count_column = VoxelDataColumnType::Count.as_str();
+
+    let merged: DataFrame = voxel_data
+        .clone()
+        .lazy()
+        .group_by(partition_columns)
+        .agg([
+            all().exclude([count_column]).first(),
+            col(count_column).sum(),
+        ])
+        .collect()?;
+
+    let merged_voxel_grid = VoxelGrid::new(
+        merged,
+        voxel_grid.info().clone(),
+        voxel_grid.reference_frames().clone(),
+    )?;
+    Ok(merged_voxel_grid)
+}
+
 pub fn explode(voxel_grid: &VoxelGrid) -> Result<VoxelGrid, Error> {
     let voxel_data = voxel_grid.voxel_data();
 
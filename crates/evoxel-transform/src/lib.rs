@@ -0,0 +1,13 @@
+mod analysis;
+mod combine;
+mod error;
+mod filter;
+mod translate;
+
+pub use analysis::{
+    connected_components, distance_field, Connectivity, DistanceMetric, DistanceSources,
+};
+pub use combine::{difference, intersection, union};
+pub use error::Error;
+pub use filter::{aggregate_by_index, explode, filter_by_count, filter_by_index_bounds};
+pub use translate::{resample, translate};
@@ -1,20 +1,79 @@
-use evoxel_core::{VoxelDataColumnType, VoxelGrid};
-use nalgebra::Vector3;
+use evoxel_core::{VoxelDataColumnType, VoxelGrid, VoxelGridInfo};
+use nalgebra::{Isometry3, Point3, Vector3};
+use polars::prelude::Series;
+
+use crate::filter::merge_duplicate_indices_summing_count;
+use crate::Error;
 
 pub fn translate(voxel_grid: &VoxelGrid, translation: Vector3<i64>) -> VoxelGrid {
-    let mut translated_data = voxel_grid.voxel_data().clone();
-    translated_data
-        .apply(VoxelDataColumnType::X.as_str(), |x| x + translation.x)
-        .expect("TODO: panic message");
-    translated_data
-        .apply(VoxelDataColumnType::Y.as_str(), |y| y + translation.y)
-        .expect("TODO: panic message");
-    translated_data
-        .apply(VoxelDataColumnType::Z.as_str(), |z| z + translation.z)
+    let mut translated_grid = voxel_grid.clone();
+    translated_grid
+        .apply_to_columns([
+            (
+                VoxelDataColumnType::X,
+                Box::new(move |x: &Series| x + translation.x) as Box<dyn FnOnce(&Series) -> Series>,
+            ),
+            (
+                VoxelDataColumnType::Y,
+                Box::new(move |y: &Series| y + translation.y) as Box<dyn FnOnce(&Series) -> Series>,
+            ),
+            (
+                VoxelDataColumnType::Z,
+                Box::new(move |z: &Series| z + translation.z) as Box<dyn FnOnce(&Series) -> Series>,
+            ),
+        ])
         .expect("TODO: panic message");
 
-    let info = voxel_grid.info().clone();
-    let frames = voxel_grid.reference_frames().clone();
+    translated_grid
+}
+
+/// Applies an arbitrary rigid transform and re-voxelizes `voxel_grid` onto `target_info`'s
+/// lattice, unlike [`translate`] which only shifts integer indices.
+///
+/// Each occupied source voxel's index is converted to a local center point
+/// (`index * resolution`), transformed by `isometry`, then divided by `target_info.resolution`
+/// and rounded to the nearest target index. This also covers resampling onto a different
+/// resolution, not just a rotation/translation. Source voxels landing on the same target index
+/// are merged per [`merge_duplicate_indices_summing_count`].
+pub fn resample(
+    voxel_grid: &VoxelGrid,
+    isometry: Isometry3<f64>,
+    target_info: VoxelGridInfo,
+) -> Result<VoxelGrid, Error> {
+    let source_resolution = voxel_grid.info().resolution;
+    let target_resolution = target_info.resolution;
+
+    let mut target_x: Vec<i64> = Vec::with_capacity(voxel_grid.size());
+    let mut target_y: Vec<i64> = Vec::with_capacity(voxel_grid.size());
+    let mut target_z: Vec<i64> = Vec::with_capacity(voxel_grid.size());
 
-    VoxelGrid::from_data_frame(translated_data, info, frames).unwrap()
+    for index in voxel_grid.get_all_cell_indices_in_local_frame() {
+        let local_center = Point3::new(
+            index.x as f64 * source_resolution,
+            index.y as f64 * source_resolution,
+            index.z as f64 * source_resolution,
+        );
+        let transformed = isometry * local_center;
+
+        target_x.push((transformed.x / target_resolution).round() as i64);
+        target_y.push((transformed.y / target_resolution).round() as i64);
+        target_z.push((transformed.z / target_resolution).round() as i64);
+    }
+
+    let mut resampled_data = voxel_grid.voxel_data().clone();
+    resampled_data.with_column(Series::new(VoxelDataColumnType::X.into(), target_x))?;
+    resampled_data.with_column(Series::new(VoxelDataColumnType::Y.into(), target_y))?;
+    resampled_data.with_column(Series::new(VoxelDataColumnType::Z.into(), target_z))?;
+
+    let resampled_voxel_grid = VoxelGrid::new(
+        resampled_data,
+        target_info,
+        voxel_grid.reference_frames().clone(),
+    )?;
+
+    merge_duplicate_indices_summing_count(&resampled_voxel_grid)
 }
+
+// No tests for `resample`'s collision handling yet: building a fixture needs `VoxelGridInfo`
+// and `ecoord::ReferenceFrames`, neither of which are part of this tree snapshot, so a fixture
+// can't be built here without guessing their constructor API.